@@ -0,0 +1,140 @@
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct FromUrl;
+
+impl Command for FromUrl {
+    fn name(&self) -> &str {
+        "from url"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from url")
+            .input_output_types(vec![(Type::String, Type::record())])
+            .switch(
+                "types",
+                "reconstruct int, float, and bool values instead of decoding everything as a string",
+                Some('t'),
+            )
+            .category(Category::Formats)
+    }
+
+    fn description(&self) -> &str {
+        "Parse url-encoded string as a record."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: "'bread=baguette&cheese=comt%C3%A9&meat=ham&fat=butter' | from url",
+                description: "Convert url encoded string into a record",
+                result: Some(Value::test_record(record! {
+                    "bread" => Value::test_string("baguette"),
+                    "cheese" => Value::test_string("comté"),
+                    "meat" => Value::test_string("ham"),
+                    "fat" => Value::test_string("butter"),
+                })),
+            },
+            Example {
+                example: "'userid=31415&active=true' | from url --types",
+                description: "Reconstruct int and bool values instead of decoding everything as a string",
+                result: Some(Value::test_record(record! {
+                    "userid" => Value::test_int(31415),
+                    "active" => Value::test_bool(true),
+                })),
+            },
+            Example {
+                example: "'a=one&a=two' | from url",
+                description: "Collapse repeated keys into a list, mirroring what `url build-query` accepts",
+                result: Some(Value::test_record(record! {
+                    "a" => Value::test_list(vec![Value::test_string("one"), Value::test_string("two")]),
+                })),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let types = call.has_flag(engine_state, stack, "types")?;
+        from_url(input, head, types)
+    }
+}
+
+fn from_url(input: PipelineData, head: Span, types: bool) -> Result<PipelineData, ShellError> {
+    let (concat_string, span, ..) = input.collect_string_strict(head)?;
+
+    let result = serde_urlencoded::from_str::<Vec<(String, String)>>(&concat_string);
+    match result {
+        Ok(pairs) => {
+            let mut record = Record::new();
+
+            for (k, v) in pairs {
+                let value = if types {
+                    parse_typed(&v, span)
+                } else {
+                    Value::string(v, span)
+                };
+
+                match record.get_mut(&k) {
+                    Some(Value::List { vals, .. }) => vals.push(value),
+                    Some(existing) => {
+                        let prev = std::mem::replace(existing, Value::nothing(span));
+                        *existing = Value::list(vec![prev, value], span);
+                    }
+                    None => {
+                        record.insert(k, value);
+                    }
+                }
+            }
+
+            Ok(Value::record(record, head).into_pipeline_data())
+        }
+        _ => Err(ShellError::UnsupportedInput {
+            msg: "String not compatible with url-encoding".to_string(),
+            input: "value originates from here".into(),
+            msg_span: head,
+            input_span: span,
+        }),
+    }
+}
+
+/// Parses a percent-decoded value as an int, then float, then bool, falling back to string.
+///
+/// A parse is only accepted if reformatting the parsed value reproduces `value` exactly, so
+/// strings like `"00501"` (leading zeros), `"nan"`, or `"inf"` stay strings instead of silently
+/// turning into `501`, `NaN`, or `f64::INFINITY`.
+fn parse_typed(value: &str, span: Span) -> Value {
+    if let Ok(i) = value.parse::<i64>() {
+        if i.to_string() == value {
+            return Value::int(i, span);
+        }
+    } else if let Ok(f) = value.parse::<f64>() {
+        if f.is_finite() && f.to_string() == value {
+            return Value::float(f, span);
+        }
+    } else if let Ok(b) = value.parse::<bool>() {
+        if b.to_string() == value {
+            return Value::bool(b, span);
+        }
+    }
+
+    Value::string(value.to_string(), span)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromUrl {})
+    }
+}
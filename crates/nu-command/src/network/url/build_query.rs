@@ -13,7 +13,24 @@ impl Command for SubCommand {
             .input_output_types(vec![
                 (Type::record(), Type::String),
                 (Type::table(), Type::String),
+                (Type::table(), Type::List(Box::new(Type::String))),
             ])
+            .named(
+                "separator",
+                SyntaxShape::String,
+                "a character to separate key-value pairs (default: &)",
+                Some('s'),
+            )
+            .switch(
+                "deep",
+                "recursively encode nested records and lists using bracket notation, e.g. user[name]=bob",
+                Some('d'),
+            )
+            .switch(
+                "rfc3986",
+                "percent-encode strictly per RFC 3986, escaping spaces as %20 instead of +",
+                None,
+            )
             .category(Category::Network)
     }
 
@@ -47,68 +64,139 @@ impl Command for SubCommand {
                 example: r#"{a: ["one", "two"], b: "three"} | url build-query"#,
                 result: Some(Value::test_string("a=one&a=two&b=three")),
             },
+            Example {
+                description: "Outputs a query string using `;` instead of `&` to separate pairs",
+                example: r#"{ mode:normal userid:31415 } | url build-query --separator ';'"#,
+                result: Some(Value::test_string("mode=normal;userid=31415")),
+            },
+            Example {
+                description: "Outputs a query string encoding nested records and lists with bracket notation",
+                example: r#"{user: {name: bob, tags: [a b]}} | url build-query --deep"#,
+                result: Some(Value::test_string(
+                    "user%5Bname%5D=bob&user%5Btags%5D%5B%5D=a&user%5Btags%5D%5B%5D=b",
+                )),
+            },
+            Example {
+                description: "Outputs a query string with spaces escaped as %20 per RFC 3986",
+                example: r#"{a: "AT T"} | url build-query --rfc3986"#,
+                result: Some(Value::test_string("a=AT%20T")),
+            },
+            Example {
+                description: "Outputs one query string per row when given a multi-row table",
+                example: r#"[[foo bar]; ["1" "2"] ["3" "4"]] | url build-query"#,
+                result: Some(Value::test_list(vec![
+                    Value::test_string("foo=1&bar=2"),
+                    Value::test_string("foo=3&bar=4"),
+                ])),
+            },
         ]
     }
 
     fn run(
         &self,
-        _engine_state: &EngineState,
-        _stack: &mut Stack,
+        engine_state: &EngineState,
+        stack: &mut Stack,
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let head = call.head;
-        to_url(input, head)
+        let separator = match call.get_flag::<Spanned<String>>(engine_state, stack, "separator")? {
+            Some(Spanned { item, span }) => {
+                let mut chars = item.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => c,
+                    _ => {
+                        return Err(ShellError::IncorrectValue {
+                            msg: "separator must be a single character".into(),
+                            val_span: span,
+                            call_span: head,
+                        })
+                    }
+                }
+            }
+            None => '&',
+        };
+        let deep = call.has_flag(engine_state, stack, "deep")?;
+        let rfc3986 = call.has_flag(engine_state, stack, "rfc3986")?;
+        to_url(input, head, separator, deep, rfc3986)
     }
 }
 
-fn to_url(input: PipelineData, head: Span) -> Result<PipelineData, ShellError> {
-    let output: Result<String, ShellError> = input
+fn to_url(
+    input: PipelineData,
+    head: Span,
+    separator: char,
+    deep: bool,
+    rfc3986: bool,
+) -> Result<PipelineData, ShellError> {
+    let output: Result<Vec<String>, ShellError> = input
         .into_iter()
         .map(move |value| {
             let span = value.span();
             match value {
                 Value::Record { ref val, .. } => {
                     let mut row_vec = vec![];
-                    for (k, v) in &**val {
-                        match v {
-                            Value::List { ref vals, .. } => {
-                                for v_item in vals {
-                                    row_vec.push((
-                                        k.clone(),
-                                        v_item.coerce_string().map_err(|_| {
-                                            ShellError::UnsupportedInput {
-                                                msg: "Expected a record with list of string values"
+                    if deep {
+                        for (k, v) in &**val {
+                            flatten_deep(k, v, head, &mut row_vec)?;
+                        }
+                    } else {
+                        for (k, v) in &**val {
+                            match v {
+                                Value::List { ref vals, .. } => {
+                                    for v_item in vals {
+                                        row_vec.push((
+                                            k.clone(),
+                                            v_item.coerce_string().map_err(|_| {
+                                                ShellError::UnsupportedInput {
+                                                    msg: "Expected a record with list of string values"
+                                                        .to_string(),
+                                                    input: "value originates from here".into(),
+                                                    msg_span: head,
+                                                    input_span: span,
+                                                }
+                                            })?,
+                                        ));
+                                    }
+                                }
+                                _ => row_vec.push((
+                                    k.clone(),
+                                    v.coerce_string()
+                                        .map_err(|_| ShellError::UnsupportedInput {
+                                            msg:
+                                                "Expected a record with string or list of string values"
                                                     .to_string(),
-                                                input: "value originates from here".into(),
-                                                msg_span: head,
-                                                input_span: span,
-                                            }
+                                            input: "value originates from here".into(),
+                                            msg_span: head,
+                                            input_span: span,
                                         })?,
-                                    ));
-                                }
+                                )),
                             }
-                            _ => row_vec.push((
-                                k.clone(),
-                                v.coerce_string()
-                                    .map_err(|_| ShellError::UnsupportedInput {
-                                        msg:
-                                            "Expected a record with string or list of string values"
-                                                .to_string(),
-                                        input: "value originates from here".into(),
-                                        msg_span: head,
-                                        input_span: span,
-                                    })?,
-                            )),
                         }
                     }
 
-                    serde_urlencoded::to_string(row_vec).map_err(|_| ShellError::CantConvert {
-                        to_type: "URL".into(),
-                        from_type: value.get_type().to_string(),
-                        span: head,
-                        help: None,
-                    })
+                    let pairs: Result<Vec<String>, ShellError> = if rfc3986 {
+                        Ok(row_vec
+                            .iter()
+                            .map(|(k, v)| format!("{}={}", rfc3986_encode(k), rfc3986_encode(v)))
+                            .collect())
+                    } else {
+                        row_vec
+                            .iter()
+                            .map(|pair| {
+                                serde_urlencoded::to_string(std::slice::from_ref(pair)).map_err(
+                                    |_| ShellError::CantConvert {
+                                        to_type: "URL".into(),
+                                        from_type: value.get_type().to_string(),
+                                        span: head,
+                                        help: None,
+                                    },
+                                )
+                            })
+                            .collect()
+                    };
+
+                    Ok(pairs?.join(&separator.to_string()))
                 }
                 // Propagate existing errors
                 Value::Error { error, .. } => Err(*error),
@@ -122,7 +210,70 @@ fn to_url(input: PipelineData, head: Span) -> Result<PipelineData, ShellError> {
         })
         .collect();
 
-    Ok(Value::string(output?, head).into_pipeline_data())
+    let rows = output?;
+    let result = match rows.len() {
+        // A single record (or 1-row table) still produces a bare string, as before.
+        0 | 1 => Value::string(rows.into_iter().next().unwrap_or_default(), head),
+        // A multi-row table produces one query string per row instead of silently
+        // concatenating them together with no delimiter.
+        _ => Value::list(
+            rows.into_iter().map(|row| Value::string(row, head)).collect(),
+            head,
+        ),
+    };
+
+    Ok(result.into_pipeline_data())
+}
+
+/// Percent-encodes `value` leaving only the RFC 3986 unreserved set (`A-Za-z0-9-._~`)
+/// untouched; everything else, including spaces, is escaped as `%XX`.
+fn rfc3986_encode(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                out.push(byte as char)
+            }
+            _ => out.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    out
+}
+
+/// Recursively walks nested records and lists, accumulating a bracketed key path
+/// (e.g. `user[tags][]`) and emitting a `(key, value)` pair at each scalar leaf.
+fn flatten_deep(
+    key: &str,
+    value: &Value,
+    head: Span,
+    pairs: &mut Vec<(String, String)>,
+) -> Result<(), ShellError> {
+    match value {
+        Value::Record { val, .. } => {
+            for (k, v) in &**val {
+                flatten_deep(&format!("{key}[{k}]"), v, head, pairs)?;
+            }
+        }
+        Value::List { vals, .. } => {
+            for v in vals {
+                flatten_deep(&format!("{key}[]"), v, head, pairs)?;
+            }
+        }
+        other => {
+            let span = other.span();
+            pairs.push((
+                key.to_string(),
+                other.coerce_string().map_err(|_| ShellError::UnsupportedInput {
+                    msg: "Expected a record with string, list, or record values".to_string(),
+                    input: "value originates from here".into(),
+                    msg_span: head,
+                    input_span: span,
+                })?,
+            ));
+        }
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]